@@ -56,6 +56,220 @@ impl<DB: Database> Describe<DB> {
     }
 }
 
+#[cfg(feature = "offline")]
+impl<DB: Database> Describe<DB>
+where
+    DB::TypeInfo: std::fmt::Display,
+    DB::Column: crate::column::Column,
+{
+    /// Flattens this `Describe` into a stable, backend-agnostic JSON shape: output columns with
+    /// their name/type/nullability, parameter types (or just a count, for backends that don't
+    /// report them), and the resolved enum variants from [`known_enum_tys`][Self::known_enum_tys].
+    ///
+    /// Intended for tooling that wants to generate types or validate queries against a live (or,
+    /// via the `offline` cache, previously recorded) `Describe` without going through the
+    /// `query!` proc-macro, the same niche Prisma's `introspectSql` fills.
+    pub fn to_introspection_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(&self.introspection())
+    }
+
+    fn introspection(&self) -> Introspection {
+        use crate::column::Column;
+
+        let columns = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| IntrospectedColumn {
+                name: column.name().to_string(),
+                type_name: column.type_info().to_string(),
+                nullable: self.nullable(i),
+            })
+            .collect();
+
+        let parameters = match self.parameters() {
+            Some(Either::Left(params)) => {
+                IntrospectedParameters::Typed(params.iter().map(ToString::to_string).collect())
+            }
+            Some(Either::Right(count)) => IntrospectedParameters::Count { count },
+            None => IntrospectedParameters::Unknown,
+        };
+
+        Introspection {
+            parameters,
+            columns,
+            enums: self
+                .known_enum_tys
+                .iter()
+                .map(|(name, variants)| (name.clone(), variants.to_vec()))
+                .collect(),
+        }
+    }
+}
+
+/// JSON-serializable, backend-agnostic snapshot of a [`Describe`].
+///
+/// See [`Describe::to_introspection_json`].
+#[cfg(feature = "offline")]
+#[derive(serde::Serialize)]
+pub struct Introspection {
+    pub parameters: IntrospectedParameters,
+    pub columns: Vec<IntrospectedColumn>,
+    pub enums: HashMap<String, Vec<String>>,
+}
+
+#[cfg(feature = "offline")]
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+pub enum IntrospectedParameters {
+    /// The full type of every parameter, in order, as reported by the driver.
+    Typed(Vec<String>),
+    /// Just how many parameters the query has; the driver couldn't report their types.
+    Count { count: usize },
+    /// The driver reported no parameter information at all.
+    Unknown,
+}
+
+#[cfg(feature = "offline")]
+#[derive(serde::Serialize)]
+pub struct IntrospectedColumn {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub nullable: Option<bool>,
+}
+
+#[cfg(all(test, feature = "offline"))]
+mod tests {
+    use super::*;
+    use crate::column::Column;
+
+    #[derive(Debug)]
+    struct FakeDb;
+
+    impl Database for FakeDb {
+        type Column = FakeColumn;
+        type TypeInfo = FakeTypeInfo;
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct FakeTypeInfo(&'static str);
+
+    impl std::fmt::Display for FakeTypeInfo {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct FakeColumn {
+        name: String,
+        type_info: FakeTypeInfo,
+    }
+
+    impl Column for FakeColumn {
+        type Database = FakeDb;
+
+        fn ordinal(&self) -> usize {
+            0
+        }
+
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn type_info(&self) -> &FakeTypeInfo {
+            &self.type_info
+        }
+    }
+
+    fn columns() -> Vec<FakeColumn> {
+        vec![
+            FakeColumn {
+                name: "id".to_string(),
+                type_info: FakeTypeInfo("INT4"),
+            },
+            FakeColumn {
+                name: "name".to_string(),
+                type_info: FakeTypeInfo("TEXT"),
+            },
+        ]
+    }
+
+    #[test]
+    fn introspection_json_reports_typed_parameters() {
+        let describe = Describe::<FakeDb> {
+            columns: columns(),
+            parameters: Some(Either::Left(vec![FakeTypeInfo("INT4"), FakeTypeInfo("TEXT")])),
+            nullable: vec![Some(false), Some(true)],
+            known_enum_tys: HashMap::new(),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&describe.to_introspection_json().unwrap()).unwrap();
+
+        assert_eq!(json["parameters"], serde_json::json!(["INT4", "TEXT"]));
+        assert_eq!(
+            json["columns"],
+            serde_json::json!([
+                {"name": "id", "type": "INT4", "nullable": false},
+                {"name": "name", "type": "TEXT", "nullable": true},
+            ])
+        );
+    }
+
+    #[test]
+    fn introspection_json_reports_parameter_count() {
+        let describe = Describe::<FakeDb> {
+            columns: Vec::new(),
+            parameters: Some(Either::Right(3)),
+            nullable: Vec::new(),
+            known_enum_tys: HashMap::new(),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&describe.to_introspection_json().unwrap()).unwrap();
+
+        assert_eq!(json["parameters"], serde_json::json!({"count": 3}));
+    }
+
+    #[test]
+    fn introspection_json_reports_unknown_parameters() {
+        let describe = Describe::<FakeDb> {
+            columns: Vec::new(),
+            parameters: None,
+            nullable: Vec::new(),
+            known_enum_tys: HashMap::new(),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&describe.to_introspection_json().unwrap()).unwrap();
+
+        assert_eq!(json["parameters"], serde_json::json!(null));
+    }
+
+    #[test]
+    fn introspection_json_reports_known_enum_variants() {
+        let describe = Describe::<FakeDb> {
+            columns: Vec::new(),
+            parameters: None,
+            nullable: Vec::new(),
+            known_enum_tys: HashMap::from([(
+                "status".to_string(),
+                Arc::from(["active".to_string(), "archived".to_string()]),
+            )]),
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&describe.to_introspection_json().unwrap()).unwrap();
+
+        assert_eq!(
+            json["enums"],
+            serde_json::json!({"status": ["active", "archived"]})
+        );
+    }
+}
+
 #[cfg(feature = "any")]
 impl<DB: Database> Describe<DB> {
     #[doc(hidden)]
@@ -97,7 +311,9 @@ impl<DB: Database> Describe<DB> {
             columns,
             parameters,
             nullable: self.nullable,
-            known_enum_tys: unreachable!("this function is never called by postgres"),
+            // `known_enum_tys` is just names and wire strings, with no DB-specific types to
+            // convert, so it carries straight through to the `any`-backed `Describe` as-is
+            known_enum_tys: self.known_enum_tys,
         })
     }
 }
@@ -4,6 +4,7 @@ use either::Either;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
 use sqlx_core::{describe::Describe, type_info::TypeInfo};
+use std::collections::HashMap;
 use syn::spanned::Spanned;
 
 /// Returns a tokenstream which typechecks the arguments passed to the macro
@@ -14,23 +15,47 @@ pub fn quote_args<DB: DatabaseExt>(
 ) -> crate::Result<TokenStream> {
     let db_path = DB::db_path();
 
-    if input.arg_exprs.is_empty() {
+    // if the query used named (`:name`) placeholders, `input.placeholder_names` holds the name
+    // bound at each positional slot in the order the backend expects them; reorder (and
+    // duplicate, if a name was repeated) `named_arg_exprs` to match before anything else runs.
+    // each resolved argument also carries its `as Type` override, if the caller wrote one.
+    let resolved_args = resolve_arg_exprs(input)?;
+    let arg_exprs = resolved_args.iter().map(|a| a.expr.clone()).collect::<Vec<_>>();
+
+    if arg_exprs.is_empty() {
         return Ok(quote! {
             let query_args = ::core::result::Result::<_, ::sqlx::error::BoxDynError>::Ok(<#db_path as ::sqlx::database::Database>::Arguments::<'_>::default());
         });
     }
 
-    let arg_names = (0..input.arg_exprs.len())
+    let arg_names = (0..arg_exprs.len())
         .map(|i| format_ident!("arg{}", i))
         .collect::<Vec<_>>();
 
-    let Some(Either::Left(params)) = info.parameters() else {
-        unimplemented!("only normal parameter inputs are supported safely");
+    // `Describe::parameters` may return fewer, the same as, or (for some drivers) no entries at
+    // all; an explicit `as Type` override lets a caller supply the type themselves in exactly
+    // the cases where the driver can't tell us, so it's checked before falling back to `info`
+    let described_params: Option<&[DB::TypeInfo]> = match info.parameters() {
+        Some(Either::Left(params)) => Some(params),
+        _ => None,
     };
 
-    let params = params
+    let params = resolved_args
         .iter()
-        .map(|param| {
+        .enumerate()
+        .map(|(i, arg)| -> crate::Result<TokenStream> {
+            if let Some(override_ty) = &arg.override_ty {
+                return Ok(quote!(#override_ty));
+            }
+
+            let Some(param) = described_params.and_then(|params| params.get(i)) else {
+                return Err(format!(
+                    "parameter {i} has no available type information and no `as _` override \
+                     was given; annotate it with `as <type>` in the query! invocation"
+                )
+                .into());
+            };
+
             let maybe_real_type = DB::param_type_for_id(param);
             let known_enum_type = info.known_enum_tys.get(param.name());
 
@@ -38,18 +63,19 @@ pub fn quote_args<DB: DatabaseExt>(
                 (Some(rt), _) => rt.parse::<TokenStream>().map_err(|err| {
                     format!("failed to parse parameter type `{param}`: {err}").into()
                 }),
-                (None, Some(et)) => {
-                    // if we have an enum, we can coerce it into a string.
-                    // TODO: add a trait that we actually require here
-                    ephemeral_enum_ty(param.name(), et)
-                }
+                (None, Some(_)) => Err(format!(
+                    "parameter {i} is bound against the database enum type `{}`, which has no \
+                     corresponding Rust type; annotate it with `as <YourEnumType>` in the query! \
+                     invocation, where `YourEnumType` is your own type for the database enum",
+                    param.name()
+                )
+                .into()),
                 _ => Err(format!("parameter type `{param}` is not supported").into()),
             }
         })
         .collect::<crate::Result<Vec<_>>>()?;
 
-    let arg_bindings = input
-        .arg_exprs
+    let arg_bindings = arg_exprs
         .iter()
         .cloned()
         .zip(params.iter())
@@ -65,7 +91,7 @@ pub fn quote_args<DB: DatabaseExt>(
 
     let args_check = params
         .iter()
-        .zip(arg_names.iter().zip(&input.arg_exprs))
+        .zip(arg_names.iter().zip(&arg_exprs))
         .map(|(param_ty, (name, expr))| -> crate::Result<_> {
             Ok(quote_spanned!(expr.span() =>
                 // this shouldn't actually run
@@ -90,7 +116,7 @@ pub fn quote_args<DB: DatabaseExt>(
         ))})
         .collect::<crate::Result<TokenStream>>()?;
 
-    let args_count = input.arg_exprs.len();
+    let args_count = arg_exprs.len();
 
     Ok(quote! {
         #arg_bindings
@@ -107,29 +133,346 @@ pub fn quote_args<DB: DatabaseExt>(
     })
 }
 
-fn ephemeral_enum_ty(name: &str, args: &[String]) -> crate::Result<TokenStream> {
-    let enum_name = format_ident!("{name}");
-
-    //     Ok(quote! {
-    //         pub enum #enum_name {
-    //             #(
-    //                 #[sqlx(rename = #args)]
-    //                 #args,
-    //             )*
-    //         }
-    //
-    //         impl ::core::convert::From<#enum_name> for ::std::string::String {
-    //             fn from(value: #enum_name) -> Self {
-    //                 match value {
-    //                     #(
-    //                         #enum_name::#args => #args.to_string(),
-    //                     )*
-    //                 }
-    //             }
-    //         }
-    //     })
+/// A single resolved argument: the expression to bind, and the type it should be bound as if the
+/// caller wrote an explicit `expr as Type` override instead of leaving it to be inferred from
+/// `Describe::parameters`.
+struct ResolvedArg {
+    expr: syn::Expr,
+    override_ty: Option<syn::Type>,
+}
+
+/// Resolves `input.arg_exprs` into the final, backend-positional argument list.
+///
+/// For the common case (purely positional `?`/`$1` placeholders), this is just `input.arg_exprs`
+/// (and their `as Type` overrides, if any) verbatim. If the query instead used named (`:name`)
+/// placeholders, `input.placeholder_names` carries the name bound at each positional slot (in
+/// backend order, with a name appearing more than once if it was repeated in the query), and the
+/// corresponding `name = expr` passed to the macro is looked up in `input.named_arg_exprs` and
+/// duplicated/reordered to match.
+///
+/// Errors clearly rather than silently dropping anything: a query with named placeholders can't
+/// also take positional arguments (and vice versa), and every `name = expr` passed in must
+/// correspond to a `:name` the query actually uses.
+fn resolve_arg_exprs(input: &QueryMacroInput) -> crate::Result<Vec<ResolvedArg>> {
+    let Some(positions) = &input.placeholder_names else {
+        if let Some(unused) = input.named_arg_exprs.keys().next() {
+            return Err(format!(
+                "argument `{unused}` was passed by name, but the query has no named (`:name`) \
+                 placeholders"
+            )
+            .into());
+        }
 
-    Ok(quote! {
-        ::std::string::String
-    })
+        return Ok(input
+            .arg_exprs
+            .iter()
+            .cloned()
+            .zip(input.arg_overrides.iter().cloned())
+            .map(|(expr, override_ty)| ResolvedArg { expr, override_ty })
+            .collect());
+    };
+
+    if !input.arg_exprs.is_empty() {
+        return Err(
+            "the query uses named (`:name`) placeholders; pass `name = expr` for every \
+             argument instead of a positional one"
+                .to_string()
+                .into(),
+        );
+    }
+
+    let mut used = HashMap::with_capacity(positions.len());
+    let mut resolved = Vec::with_capacity(positions.len());
+
+    for name in positions {
+        let expr = input.named_arg_exprs.get(name).ok_or_else(|| {
+            format!("query uses placeholder `:{name}` but no argument named `{name}` was passed")
+        })?;
+
+        used.insert(name.as_str(), ());
+        resolved.push(ResolvedArg {
+            expr: expr.clone(),
+            override_ty: input.named_arg_overrides.get(name).cloned(),
+        });
+    }
+
+    if let Some(unused) = input
+        .named_arg_exprs
+        .keys()
+        .find(|name| !used.contains_key(name.as_str()))
+    {
+        return Err(
+            format!("argument `{unused}` was passed to the query but is not used by it").into(),
+        );
+    }
+
+    Ok(resolved)
+}
+
+/// Which positional placeholder syntax a backend expects after named placeholders are rewritten.
+pub(crate) enum PlaceholderStyle {
+    /// `$1`, `$2`, ... (Postgres)
+    Dollar,
+    /// A single repeated `?` (MySQL, SQLite)
+    QuestionMark,
+}
+
+/// Scans `sql` for named placeholders (`:name`) and rewrites them to the backend's positional
+/// placeholder syntax, returning the rewritten SQL along with the name bound at each positional
+/// slot, in order (a name appears more than once if the same `:name` occurs more than once).
+///
+/// String/quoted-identifier literals and `--`/`/* */` comments are copied through verbatim so a
+/// `:` inside one of those is never mistaken for a placeholder, and a bare `::` (the Postgres
+/// cast operator) is left untouched. For [`PlaceholderStyle::Dollar`], any `$N` placeholders
+/// already present in `sql` (a query mixing positional and named syntax) are scanned for first,
+/// so generated placeholders are numbered past the highest one already there instead of
+/// colliding with it.
+pub(crate) fn rewrite_named_placeholders(sql: &str, style: PlaceholderStyle) -> (String, Vec<String>) {
+    let dollar_offset = match style {
+        PlaceholderStyle::Dollar => highest_existing_dollar_placeholder(sql),
+        PlaceholderStyle::QuestionMark => 0,
+    };
+
+    let mut out = String::with_capacity(sql.len());
+    let mut names = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                out.push(c);
+                while let Some((_, next)) = chars.next() {
+                    out.push(next);
+                    if next == c {
+                        // a doubled quote (`''`, `""`) is an escaped quote, not the closing one
+                        if matches!(chars.peek(), Some((_, p)) if *p == c) {
+                            out.push(chars.next().unwrap().1);
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                out.push(c);
+                for (_, next) in chars.by_ref() {
+                    out.push(next);
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                out.push(c);
+                out.push(chars.next().unwrap().1);
+                let mut prev = '\0';
+                for (_, next) in chars.by_ref() {
+                    out.push(next);
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            ':' if matches!(chars.peek(), Some((_, ':'))) => {
+                // `::`, the Postgres cast operator, not a placeholder
+                out.push(c);
+                out.push(chars.next().unwrap().1);
+            }
+            ':' if matches!(chars.peek(), Some((_, p)) if p.is_alphabetic() || *p == '_') => {
+                let mut name = String::new();
+                while matches!(chars.peek(), Some((_, p)) if p.is_alphanumeric() || *p == '_') {
+                    name.push(chars.next().unwrap().1);
+                }
+
+                match style {
+                    PlaceholderStyle::Dollar => {
+                        out.push_str(&format!("${}", dollar_offset + names.len() + 1))
+                    }
+                    PlaceholderStyle::QuestionMark => out.push('?'),
+                }
+
+                names.push(name);
+            }
+            _ => out.push(c),
+        }
+    }
+
+    (out, names)
+}
+
+/// Scans `sql` for existing `$<digits>` positional placeholders, ignoring occurrences inside
+/// string/quoted-identifier literals and comments (the same exclusions `rewrite_named_placeholders`
+/// itself applies), and returns the highest number found, or `0` if there are none.
+fn highest_existing_dollar_placeholder(sql: &str) -> usize {
+    let mut highest = 0;
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                while let Some((_, next)) = chars.next() {
+                    if next == c {
+                        if matches!(chars.peek(), Some((_, p)) if *p == c) {
+                            chars.next();
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                for (_, next) in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                chars.next();
+                let mut prev = '\0';
+                for (_, next) in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            '$' if matches!(chars.peek(), Some((_, p)) if p.is_ascii_digit()) => {
+                let mut digits = String::new();
+                while matches!(chars.peek(), Some((_, p)) if p.is_ascii_digit()) {
+                    digits.push(chars.next().unwrap().1);
+                }
+
+                if let Ok(n) = digits.parse::<usize>() {
+                    highest = highest.max(n);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    highest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrite_named_placeholders_basic() {
+        let (sql, names) =
+            rewrite_named_placeholders("SELECT * FROM t WHERE id = :id AND status = :status", PlaceholderStyle::Dollar);
+
+        assert_eq!(sql, "SELECT * FROM t WHERE id = $1 AND status = $2");
+        assert_eq!(names, vec!["id".to_string(), "status".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_repeated_name() {
+        let (sql, names) = rewrite_named_placeholders(
+            "SELECT * FROM t WHERE a = :x OR b = :x",
+            PlaceholderStyle::QuestionMark,
+        );
+
+        assert_eq!(sql, "SELECT * FROM t WHERE a = ? OR b = ?");
+        assert_eq!(names, vec!["x".to_string(), "x".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_ignores_string_literals() {
+        let (sql, names) = rewrite_named_placeholders(
+            "SELECT ':not_a_placeholder' AS label, col FROM t WHERE id = :id",
+            PlaceholderStyle::Dollar,
+        );
+
+        assert_eq!(sql, "SELECT ':not_a_placeholder' AS label, col FROM t WHERE id = $1");
+        assert_eq!(names, vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_ignores_line_comments() {
+        let (sql, names) = rewrite_named_placeholders(
+            "SELECT col -- uses :not_a_placeholder here\nFROM t WHERE id = :id",
+            PlaceholderStyle::Dollar,
+        );
+
+        assert_eq!(
+            sql,
+            "SELECT col -- uses :not_a_placeholder here\nFROM t WHERE id = $1"
+        );
+        assert_eq!(names, vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_ignores_block_comments() {
+        let (sql, names) = rewrite_named_placeholders(
+            "SELECT col /* :not_a_placeholder */ FROM t WHERE id = :id",
+            PlaceholderStyle::Dollar,
+        );
+
+        assert_eq!(
+            sql,
+            "SELECT col /* :not_a_placeholder */ FROM t WHERE id = $1"
+        );
+        assert_eq!(names, vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_ignores_postgres_cast() {
+        let (sql, names) =
+            rewrite_named_placeholders("SELECT :val::text FROM t", PlaceholderStyle::Dollar);
+
+        assert_eq!(sql, "SELECT $1::text FROM t");
+        assert_eq!(names, vec!["val".to_string()]);
+    }
+
+    #[test]
+    fn rewrite_named_placeholders_offsets_past_existing_dollar_placeholder() {
+        // `$1` is already spoken for; the generated placeholder for `:status` must not reuse it.
+        let (sql, names) = rewrite_named_placeholders(
+            "SELECT * FROM t WHERE a = $1 AND b = :status",
+            PlaceholderStyle::Dollar,
+        );
+
+        assert_eq!(sql, "SELECT * FROM t WHERE a = $1 AND b = $2");
+        assert_eq!(names, vec!["status".to_string()]);
+    }
+
+    #[test]
+    fn resolve_arg_exprs_errors_on_named_arg_for_positional_query() {
+        let input = QueryMacroInput {
+            sql: "SELECT * FROM t WHERE id = $1".to_string(),
+            sql_span: proc_macro2::Span::call_site(),
+            arg_exprs: Vec::new(),
+            arg_overrides: Vec::new(),
+            named_arg_exprs: HashMap::from([(
+                "status".to_string(),
+                syn::parse_str::<syn::Expr>("foo").unwrap(),
+            )]),
+            named_arg_overrides: HashMap::new(),
+            placeholder_names: None,
+        };
+
+        let err = match resolve_arg_exprs(&input) {
+            Ok(_) => panic!("should error, not silently drop `status`"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("status"));
+    }
+
+    #[test]
+    fn resolve_arg_exprs_errors_on_positional_arg_for_named_query() {
+        let input = QueryMacroInput {
+            sql: "SELECT * FROM t WHERE id = :id".to_string(),
+            sql_span: proc_macro2::Span::call_site(),
+            arg_exprs: vec![syn::parse_str::<syn::Expr>("foo").unwrap()],
+            arg_overrides: vec![None],
+            named_arg_exprs: HashMap::new(),
+            named_arg_overrides: HashMap::new(),
+            placeholder_names: Some(vec!["id".to_string()]),
+        };
+
+        assert!(resolve_arg_exprs(&input).is_err());
+    }
 }
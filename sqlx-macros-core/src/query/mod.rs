@@ -0,0 +1,156 @@
+//! Parsing for the input tokens of the `query!`-family macros.
+
+mod args;
+
+pub use args::quote_args;
+
+use args::{rewrite_named_placeholders, PlaceholderStyle};
+use proc_macro2::Span;
+use std::collections::HashMap;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, LitStr, Token, Type,
+};
+
+/// The parsed input to a `query!`/`query_as!`/... macro invocation.
+pub struct QueryMacroInput {
+    /// The query's SQL text. Any named (`:name`) placeholders have already been rewritten to the
+    /// backend's positional syntax by the time parsing finishes, so this is always what gets
+    /// handed to `DB::describe` and the query executor.
+    pub sql: String,
+    pub sql_span: Span,
+
+    /// Positional argument expressions (`query!("...", arg1, arg2)`), in the order they appear.
+    pub arg_exprs: Vec<Expr>,
+    /// The `as Type` override for each entry in `arg_exprs`, `None` where none was given.
+    pub arg_overrides: Vec<Option<Type>>,
+
+    /// `name = expr` arguments (`query!("...", status = status)`), keyed by name.
+    pub named_arg_exprs: HashMap<String, Expr>,
+    /// The `as Type` override for each entry in `named_arg_exprs`, keyed by the same name.
+    pub named_arg_overrides: HashMap<String, Type>,
+
+    /// If `sql` used named (`:name`) placeholders, the name bound at each positional slot, in
+    /// order (a name appears more than once if the same `:name` occurred more than once in the
+    /// query). `None` for a query that only used positional placeholders.
+    pub placeholder_names: Option<Vec<String>>,
+}
+
+impl Parse for QueryMacroInput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let sql_lit = input.parse::<LitStr>()?;
+        let sql_span = sql_lit.span();
+        let raw_sql = sql_lit.value();
+
+        let (sql, placeholder_names) = if has_named_placeholder(&raw_sql) {
+            let (sql, names) = rewrite_named_placeholders(&raw_sql, placeholder_style());
+            // the quick check may have fired on a `:` inside a literal, comment or `::` cast;
+            // the query only counts as using named placeholders if the real scan found one
+            (sql, (!names.is_empty()).then_some(names))
+        } else {
+            (raw_sql, None)
+        };
+
+        let mut arg_exprs = Vec::new();
+        let mut arg_overrides = Vec::new();
+        let mut named_arg_exprs = HashMap::new();
+        let mut named_arg_overrides = HashMap::new();
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+
+            // allow a trailing comma after the last argument
+            if input.is_empty() {
+                break;
+            }
+
+            let (name, expr, override_ty) = parse_arg(input)?;
+
+            match name {
+                Some(name) => {
+                    named_arg_exprs.insert(name.clone(), expr);
+
+                    if let Some(ty) = override_ty {
+                        named_arg_overrides.insert(name, ty);
+                    }
+                }
+                None => {
+                    arg_exprs.push(expr);
+                    arg_overrides.push(override_ty);
+                }
+            }
+        }
+
+        Ok(QueryMacroInput {
+            sql,
+            sql_span,
+            arg_exprs,
+            arg_overrides,
+            named_arg_exprs,
+            named_arg_overrides,
+            placeholder_names,
+        })
+    }
+}
+
+/// Parses one macro argument: either a bare `expr`, or a named `name = expr`, with an optional
+/// `as Type` override on either form (e.g. `my_value as OffsetDateTime`).
+///
+/// `name = expr` and `expr as Type` both already parse as ordinary `syn::Expr` variants
+/// (`Expr::Assign`, `Expr::Cast`), so rather than hand-rolling a parser we parse a single `Expr`
+/// and then pick it apart.
+fn parse_arg(input: ParseStream<'_>) -> syn::Result<(Option<String>, Expr, Option<Type>)> {
+    let expr = input.parse::<Expr>()?;
+
+    let (name, expr) = match expr {
+        Expr::Assign(assign) => {
+            let name = match *assign.left {
+                Expr::Path(path) if path.path.get_ident().is_some() => {
+                    path.path.get_ident().unwrap().to_string()
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "expected a bare identifier on the left-hand side of `=`",
+                    ))
+                }
+            };
+
+            (Some(name), *assign.right)
+        }
+        other => (None, other),
+    };
+
+    let (expr, override_ty) = match expr {
+        Expr::Cast(cast) => (*cast.expr, Some(*cast.ty)),
+        other => (other, None),
+    };
+
+    Ok((name, expr, override_ty))
+}
+
+/// Which positional placeholder syntax a backend expects after named placeholders are rewritten.
+///
+/// The query macros are compiled against exactly one backend's Cargo feature at a time in the
+/// common case, so this is resolved at compile time of this crate rather than threaded through
+/// from the (separately, runtime-resolved) `DB` type used later on for typechecking.
+fn placeholder_style() -> PlaceholderStyle {
+    if cfg!(feature = "postgres") {
+        PlaceholderStyle::Dollar
+    } else {
+        PlaceholderStyle::QuestionMark
+    }
+}
+
+/// Cheaply checks whether `sql` contains anything that looks like a `:name` placeholder, so the
+/// (more expensive, allocating) scan in [`rewrite_named_placeholders`] can be skipped for the
+/// common case of a query with none.
+///
+/// This is intentionally not quote/comment-aware; a false positive here just costs an extra,
+/// otherwise-harmless scan, since `rewrite_named_placeholders` itself does the real work of
+/// ignoring placeholders that appear inside a string, quoted identifier, or comment.
+fn has_named_placeholder(sql: &str) -> bool {
+    sql.chars()
+        .zip(sql.chars().skip(1))
+        .any(|(c, next)| c == ':' && (next.is_alphabetic() || next == '_'))
+}